@@ -4,7 +4,9 @@ use std::time::Duration;
 
 use bevy::prelude::*;
 use bevy_arrows_plugin::{
-    BevyArrowsPlugin,
+    ArrowRenderMode, BevyArrowsPlugin,
+    poly_arrow::{PolyArrow, PolyArrowCoordinateSpace},
+    rot_arrow::RotArrow,
     vec_arrow::{TargetCoordinateSpace, VecArrow},
 };
 use bevy_inspector_egui::quick::WorldInspectorPlugin;
@@ -17,8 +19,15 @@ fn main() {
         // helpers
         .add_plugins(WorldInspectorPlugin::new())
         .add_plugins(TweeningPlugin)
-        // our plugin
-        .add_plugins(BevyArrowsPlugin)
+        // our plugin: run with `--features gizmo_render` to see the VecArrows
+        // rendered as immediate-mode line segments instead of meshes (the
+        // RotArrow and PolyArrow are still meshes).
+        .add_plugins(BevyArrowsPlugin {
+            #[cfg(feature = "gizmo_render")]
+            mode: ArrowRenderMode::Gizmo,
+            #[cfg(not(feature = "gizmo_render"))]
+            mode: ArrowRenderMode::Mesh,
+        })
         // systems
         .add_systems(Startup, setup)
         .add_systems(Startup, setup_instructions)
@@ -50,7 +59,7 @@ fn setup(
         Name::new("Base"),
     ));
     // cube
-    commands
+    let cube = commands
         .spawn((
             Mesh3d(meshes.add(Cuboid::new(1.0, 1.0, 1.0))),
             MeshMaterial3d(materials.add(Color::srgb_u8(124, 144, 255))),
@@ -77,15 +86,48 @@ fn setup(
             Name::new("XY arrow"),
             VecArrow::new(Vec3::new(2.0, 2.0, 0.0), TargetCoordinateSpace::Local)
                 .with_color(Color::linear_rgb(1.0, 1.0, 0.0)),
-        ));
+        ))
+        .with_child((
+            // Shows the rotation Space will roll the cube to; updated in
+            // on_space_press_roll each time a new random quaternion is picked.
+            Name::new("Roll-preview arc"),
+            RotArrow::new(Quat::IDENTITY, 1.5).with_color(Color::linear_rgb(1.0, 0.5, 0.0)),
+        ))
+        .id();
     // light
+    commands
+        .spawn((
+            PointLight {
+                shadows_enabled: true,
+                ..default()
+            },
+            Transform::from_xyz(4.0, 8.0, 4.0),
+            Name::new("Light"),
+        ))
+        .with_child((
+            // Demonstrates TargetCoordinateSpace::Entity: this arrow tracks
+            // the cube's live GlobalTransform instead of a fixed position, so
+            // it keeps pointing at the cube as WASD/Q/E move it around.
+            Name::new("Light-to-cube arrow"),
+            VecArrow::new(Vec3::ZERO, TargetCoordinateSpace::Entity(cube))
+                .with_color(Color::linear_rgb(1.0, 1.0, 1.0)),
+        ));
+
+    // Demonstrates PolyArrow: a multi-segment waypoint chain, like a bone
+    // chain or an IK target path, sitting beside the base.
     commands.spawn((
-        PointLight {
-            shadows_enabled: true,
-            ..default()
-        },
-        Transform::from_xyz(4.0, 8.0, 4.0),
-        Name::new("Light"),
+        Transform::IDENTITY,
+        Name::new("Waypoint chain"),
+        PolyArrow::new(
+            vec![
+                Vec3::new(-3.0, 0.0, -3.0),
+                Vec3::new(-3.0, 1.0, -2.0),
+                Vec3::new(-2.0, 1.5, -1.0),
+                Vec3::new(-2.0, 1.0, 0.0),
+            ],
+            PolyArrowCoordinateSpace::Global,
+        )
+        .with_color(Color::linear_rgb(0.0, 1.0, 1.0)),
     ));
 
     // empty object at the center of the world
@@ -128,6 +170,7 @@ fn on_space_press_roll(
     mut commands: Commands,
     keypresses: Res<ButtonInput<KeyCode>>,
     query: Query<(Entity, &Transform), With<CubeMarker>>,
+    mut roll_preview: Query<&mut RotArrow>,
 ) {
     if keypresses.just_pressed(KeyCode::Space) {
         // Get the current rotation of the cube
@@ -159,6 +202,9 @@ fn on_space_press_roll(
         );
 
         commands.entity(entity).insert(Animator::new(tween));
+
+        // Show the rotation the tween is about to apply as an arc.
+        roll_preview.single_mut().rotation = dest * transform.rotation.inverse();
     }
 }
 
@@ -171,6 +217,9 @@ fn on_tab_press_toggle_coordinate_space(
             arrow.target_coordinate_space = match arrow.target_coordinate_space {
                 TargetCoordinateSpace::Global => TargetCoordinateSpace::Local,
                 TargetCoordinateSpace::Local => TargetCoordinateSpace::Global,
+                // Tab only cycles between the two fixed coordinate spaces;
+                // an entity-tracking target isn't affected.
+                entity @ TargetCoordinateSpace::Entity(_) => entity,
             }
         }
     }
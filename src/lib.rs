@@ -1,20 +1,73 @@
+// Bevy systems routinely take more than clippy's default argument limit of
+// queries/resources; splitting them up would hurt readability for no benefit.
+#![allow(clippy::too_many_arguments)]
+
+pub mod poly_arrow;
+pub mod rot_arrow;
 pub mod vec_arrow;
-use bevy::app::{Plugin, PostUpdate};
+use bevy::app::{Plugin, PostUpdate, Startup};
 
 /// This plugin adds systems that keep track of the [`vec_arrow::VecArrow`] components,
 /// and updates the arrow items accordingly.
 #[derive(Default, Debug, Clone, Copy)]
-pub struct BevyArrowsPlugin;
+pub struct BevyArrowsPlugin {
+    /// How [`vec_arrow::VecArrow`]s are rendered. Defaults to
+    /// [`ArrowRenderMode::Mesh`]. [`rot_arrow::RotArrow`] and
+    /// [`poly_arrow::PolyArrow`] are always mesh-rendered regardless of this
+    /// setting.
+    pub mode: ArrowRenderMode,
+}
+
+/// Selects how [`vec_arrow::VecArrow`] components are turned into visible arrows.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrowRenderMode {
+    /// Spawn a shaft entity using a shared unit `Cylinder` mesh and a tip
+    /// entity using a shared unit `Cone` mesh, with a `StandardMaterial`
+    /// shared by all arrows of the same color. Looks better, but costs two
+    /// entities per arrow.
+    #[default]
+    Mesh,
+
+    /// Draw arrows as immediate-mode line segments via Bevy's `Gizmos`,
+    /// without spawning any entities, meshes or materials. Cheaper for large
+    /// numbers of transient debug arrows, at the cost of a thinner, flat-shaded
+    /// look.
+    ///
+    /// Requires the `gizmo_render` cargo feature.
+    #[cfg(feature = "gizmo_render")]
+    Gizmo,
+}
 
 impl Plugin for BevyArrowsPlugin {
     fn build(&self, app: &mut bevy::app::App) {
-        app.add_systems(PostUpdate, vec_arrow::on_attach_vec_arrow);
-        app.add_systems(PostUpdate, vec_arrow::on_remove_vec_arrow);
-        app.add_systems(PostUpdate, vec_arrow::update_vec_arrow);
+        app.add_systems(Startup, vec_arrow::setup_vec_arrow_meshes);
+        app.init_resource::<vec_arrow::VecArrowMaterials>();
+
+        match self.mode {
+            ArrowRenderMode::Mesh => {
+                app.add_systems(PostUpdate, vec_arrow::on_attach_vec_arrow);
+                app.add_systems(PostUpdate, vec_arrow::on_remove_vec_arrow);
+                app.add_systems(PostUpdate, vec_arrow::update_vec_arrow);
+            }
+            #[cfg(feature = "gizmo_render")]
+            ArrowRenderMode::Gizmo => {
+                app.add_systems(PostUpdate, vec_arrow::draw_vec_arrow_gizmos);
+            }
+        }
+
+        app.add_systems(PostUpdate, rot_arrow::on_attach_rot_arrow);
+        app.add_systems(PostUpdate, rot_arrow::on_remove_rot_arrow);
+        app.add_systems(PostUpdate, rot_arrow::update_rot_arrow);
+
+        app.add_systems(PostUpdate, poly_arrow::on_attach_poly_arrow);
+        app.add_systems(PostUpdate, poly_arrow::on_remove_poly_arrow);
+        app.add_systems(PostUpdate, poly_arrow::update_poly_arrow);
     }
 }
 
 pub mod prelude {
-    pub use crate::BevyArrowsPlugin;
+    pub use crate::poly_arrow::PolyArrow;
+    pub use crate::rot_arrow::RotArrow;
     pub use crate::vec_arrow::VecArrow;
+    pub use crate::{ArrowRenderMode, BevyArrowsPlugin};
 }
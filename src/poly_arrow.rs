@@ -0,0 +1,321 @@
+use bevy::prelude::*;
+
+use crate::vec_arrow::{VecArrowMaterials, VecArrowMeshes};
+
+/// What coordinate system [`PolyArrow::points`] is in.
+///
+/// Unlike [`crate::vec_arrow::TargetCoordinateSpace`], there's no `Entity`
+/// variant here: a poly-arrow's waypoints don't have a single target entity
+/// to resolve each of them against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolyArrowCoordinateSpace {
+    /// `points` are in the coordinate space local to the object.
+    Local,
+
+    /// `points` are absolute positions in the global coordinate space.
+    Global,
+}
+
+/// A chain of straight shaft segments connecting a list of waypoints,
+/// with a single cone tip at the last point.
+///
+/// Useful for visualizing things like bone chains or IK target paths, where
+/// a single straight [`crate::vec_arrow::VecArrow`] isn't enough.
+#[derive(Component)]
+pub struct PolyArrow {
+    /// The waypoints the arrow passes through, interpreted according to
+    /// `target_coordinate_space`. Fewer than two points renders nothing.
+    pub points: Vec<Vec3>,
+
+    /// What coordinate system `points` is in.
+    pub target_coordinate_space: PolyArrowCoordinateSpace,
+
+    /// Thickness of the line in scene units.
+    pub thickness: f32,
+
+    /// Color of the line and the tip.
+    pub color: Color,
+
+    /// Thickness of the tip (diameter at the bottom of the arrow)
+    pub tip_thickness: f32,
+
+    /// Length of the tip
+    pub tip_length: f32,
+}
+
+impl PolyArrow {
+    pub fn new(points: Vec<Vec3>, target_coordinate_space: PolyArrowCoordinateSpace) -> Self {
+        Self {
+            points,
+            target_coordinate_space,
+            thickness: 0.1,
+            color: Color::WHITE,
+            tip_thickness: 0.075,
+            tip_length: 0.15,
+        }
+    }
+
+    pub const fn with_thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// This component is used by the plugin internally
+/// and marks one of the shaft segments between two waypoints
+/// (which is a cylinder).
+#[derive(Component)]
+pub(crate) struct PolyArrowSegment {}
+
+/// This component is used by the plugin internally
+/// and marks the tip of the arrow
+/// (which is a cone).
+#[derive(Component)]
+pub(crate) struct PolyArrowTip {}
+
+/// This component is used by the plugin internally
+/// to store the Entity ids for the arrow parts.
+/// This is used when the arrow is removed
+/// to find the other entities to also remove.
+#[derive(Component, Clone, Debug)]
+pub(crate) struct PolyArrowParts {
+    segments: Vec<Entity>,
+    tip: Entity,
+}
+
+pub(crate) fn on_attach_poly_arrow(
+    mut commands: Commands,
+    arrow_meshes: Res<VecArrowMeshes>,
+    mut arrow_materials: ResMut<VecArrowMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    query: Query<(Entity, Option<&GlobalTransform>, &PolyArrow), Added<PolyArrow>>,
+) {
+    // When a poly-arrow is added,
+    // we need to create a cylinder per segment and a single cone, reusing
+    // the shared unit meshes and the cached material for this arrow's color
+    // instead of allocating one of each per segment.
+    for (new_parent_entity, parent_global_transform, new_arrow) in query.iter() {
+        // Ensure the parent has Visibility and Transform components
+        commands
+            .entity(new_parent_entity)
+            .insert_if_new(Visibility::Inherited)
+            .insert_if_new(Transform::default());
+
+        let world_points = resolve_world_points(new_arrow, parent_global_transform.cloned());
+
+        let material = arrow_materials.get_or_insert(new_arrow.color, &mut materials);
+
+        let segments = segment_transforms(&world_points, new_arrow.thickness)
+            .into_iter()
+            .map(|segment_transform| {
+                commands
+                    .spawn((
+                        Mesh3d(arrow_meshes.cylinder()),
+                        MeshMaterial3d(material.clone()),
+                        segment_transform,
+                        PolyArrowSegment {},
+                        Name::new(format!("PolyArrowSegment for {}", new_parent_entity)),
+                    ))
+                    .id()
+            })
+            .collect();
+
+        let tip = commands
+            .spawn((
+                Mesh3d(arrow_meshes.cone()),
+                MeshMaterial3d(material),
+                tip_transform(&world_points, new_arrow.tip_length, new_arrow.tip_thickness),
+                Name::new(format!("PolyArrowTip for {}", new_parent_entity)),
+                PolyArrowTip {},
+            ))
+            .id();
+
+        commands
+            .entity(new_parent_entity)
+            .insert(PolyArrowParts { segments, tip });
+    }
+}
+
+pub(crate) fn on_remove_poly_arrow(
+    mut commands: Commands,
+    mut parents_with_removed_arrows: RemovedComponents<PolyArrow>,
+    parent_state_query: Query<Option<&PolyArrowParts>>,
+) {
+    for entity in parents_with_removed_arrows.read() {
+        // entity has just had its PolyArrow component removed by the user.
+        // If the entity has despawned completely, then we can't find its children.
+        // Otherwise, we can read the PolyArrowParts,
+        // which we use to keep track of the arrow components.
+        if let Ok(Some(PolyArrowParts { segments, tip })) = parent_state_query.get(entity) {
+            // Despawn the arrow parts
+            for segment in segments {
+                commands.entity(*segment).despawn();
+            }
+            commands.entity(*tip).despawn();
+
+            // Remove PolyArrowParts from the parent
+            commands.entity(entity).remove::<PolyArrowParts>();
+        }
+    }
+}
+
+pub(crate) fn update_poly_arrow(
+    mut commands: Commands,
+    arrow_meshes: Res<VecArrowMeshes>,
+    mut arrow_materials: ResMut<VecArrowMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut parent_transforms: Query<(
+        Entity,
+        Option<&GlobalTransform>,
+        &PolyArrow,
+        &mut PolyArrowParts,
+    )>,
+    mut segment_query: Query<&mut Transform, (With<PolyArrowSegment>, Without<PolyArrowTip>)>,
+    mut tip_query: Query<&mut Transform, (With<PolyArrowTip>, Without<PolyArrowSegment>)>,
+    segment_material_query: Query<&MeshMaterial3d<StandardMaterial>, With<PolyArrowSegment>>,
+    changed_parents: Query<Entity, (With<PolyArrowParts>, Changed<PolyArrow>)>,
+) {
+    for (parent_entity, parent_global_transform, poly_arrow, mut parts) in
+        parent_transforms.iter_mut()
+    {
+        let world_points = resolve_world_points(poly_arrow, parent_global_transform.copied());
+        let new_segment_transforms = segment_transforms(&world_points, poly_arrow.thickness);
+
+        // `points` may have changed length since attach (or since last
+        // frame): resize `parts.segments` to match, instead of leaving
+        // stale segments on screen or silently dropping new waypoints.
+        let kept = parts.segments.len().min(new_segment_transforms.len());
+
+        for (segment, new_segment_transform) in parts.segments[..kept]
+            .iter()
+            .zip(&new_segment_transforms[..kept])
+        {
+            *segment_query.get_mut(*segment).unwrap() = *new_segment_transform;
+        }
+
+        for segment in parts.segments.drain(kept..) {
+            commands.entity(segment).despawn();
+        }
+
+        if kept < new_segment_transforms.len() {
+            // New segments reuse this arrow's cached material handle (the
+            // same color-keyed cache the rest of the arrow uses), instead of
+            // allocating a fresh `StandardMaterial` every time the waypoint
+            // list grows.
+            let material = arrow_materials.get_or_insert(poly_arrow.color, &mut materials);
+            for new_segment_transform in &new_segment_transforms[kept..] {
+                let segment = commands
+                    .spawn((
+                        Mesh3d(arrow_meshes.cylinder()),
+                        MeshMaterial3d(material.clone()),
+                        *new_segment_transform,
+                        PolyArrowSegment {},
+                        Name::new(format!("PolyArrowSegment for {}", parent_entity)),
+                    ))
+                    .id();
+                parts.segments.push(segment);
+            }
+        }
+
+        let new_tip_transform = tip_transform(
+            &world_points,
+            poly_arrow.tip_length,
+            poly_arrow.tip_thickness,
+        );
+        *tip_query.get_mut(parts.tip).unwrap() = new_tip_transform;
+    }
+
+    // Same guard as `vec_arrow::update_vec_arrow`: only touch the material
+    // when the cached handle for the current color differs from what's
+    // already applied, instead of writing `base_color` into it every frame
+    // for every segment.
+    for changed_entity in changed_parents.iter() {
+        let Ok((_, _, poly_arrow, parts)) = parent_transforms.get(changed_entity) else {
+            continue;
+        };
+        let material = arrow_materials.get_or_insert(poly_arrow.color, &mut materials);
+        let already_applied = parts
+            .segments
+            .first()
+            .and_then(|segment| segment_material_query.get(*segment).ok())
+            .is_some_and(|current| current.0 == material);
+        if already_applied {
+            continue;
+        }
+        for segment in &parts.segments {
+            commands
+                .entity(*segment)
+                .insert(MeshMaterial3d(material.clone()));
+        }
+        commands.entity(parts.tip).insert(MeshMaterial3d(material));
+    }
+}
+
+/// Resolves `poly_arrow.points` to world-space positions, applying the
+/// parent's rotation and translation when the coordinate space is local
+/// (the same selective transform used by [`crate::vec_arrow`]'s `Local`
+/// branch, leaving scale out).
+fn resolve_world_points(
+    poly_arrow: &PolyArrow,
+    parent_transform: Option<GlobalTransform>,
+) -> Vec<Vec3> {
+    match poly_arrow.target_coordinate_space {
+        PolyArrowCoordinateSpace::Global => poly_arrow.points.clone(),
+        PolyArrowCoordinateSpace::Local => {
+            let parent_transform = parent_transform.unwrap_or_default();
+            poly_arrow
+                .points
+                .iter()
+                .map(|point| parent_transform.rotation() * *point + parent_transform.translation())
+                .collect()
+        }
+    }
+}
+
+/// Builds one cylinder transform per consecutive pair of `world_points`.
+/// Coincident points produce a zero-scale segment instead of being skipped,
+/// so the result always has `world_points.len().saturating_sub(1)` entries.
+fn segment_transforms(world_points: &[Vec3], thickness: f32) -> Vec<Transform> {
+    world_points
+        .windows(2)
+        .map(|pair| {
+            let [start, end] = [pair[0], pair[1]];
+            let segment = end - start;
+            let Some(normalized) = segment.try_normalize() else {
+                return Transform::from_scale(Vec3::ZERO);
+            };
+
+            let midpoint = (start + end) / 2.0;
+            let mut transform = Transform::from_translation(midpoint);
+            transform.rotate(Quat::from_rotation_arc(Vec3::Y, normalized));
+            // X, Z scale matches the shaft thickness, Y scale the segment's
+            // length, mirroring the tip's `tip_transform` scaling.
+            transform.scale = Vec3::new(thickness, segment.length(), thickness);
+            transform
+        })
+        .collect()
+}
+
+/// Builds the cone tip transform at the last point, oriented along the
+/// direction of the final segment. Renders nothing if there are fewer than
+/// two points, or if the last two points coincide.
+fn tip_transform(world_points: &[Vec3], tip_length: f32, tip_thickness: f32) -> Transform {
+    let [second_to_last, last] = match world_points {
+        [.., second_to_last, last] => [*second_to_last, *last],
+        _ => return Transform::from_scale(Vec3::ZERO),
+    };
+
+    let Some(normalized) = (last - second_to_last).try_normalize() else {
+        return Transform::from_scale(Vec3::ZERO);
+    };
+
+    let mut transform = Transform::from_translation(last);
+    transform.rotate(Quat::from_rotation_arc(Vec3::Y, normalized));
+    transform.scale = Vec3::new(tip_thickness, tip_length, tip_thickness);
+    transform
+}
@@ -0,0 +1,297 @@
+use bevy::prelude::*;
+
+use crate::vec_arrow::{VecArrowMaterials, VecArrowMeshes};
+
+/// How many straight segments approximate the curved arc.
+const ROT_ARROW_SEGMENTS: usize = 16;
+
+/// An arrow sweeping through the angle of a [`Quat`], along an arc of
+/// `radius` centered on the object's transform.
+///
+/// Useful for visualizing an orientation or a rotation that will be applied,
+/// the same way the turntable example animates cube rolls with random
+/// quaternions.
+#[derive(Component)]
+pub struct RotArrow {
+    /// The rotation to visualize.
+    pub rotation: Quat,
+
+    /// Radius of the arc, in scene units.
+    pub radius: f32,
+
+    /// Thickness of the line in scene units.
+    pub thickness: f32,
+
+    /// Color of the line and the tip.
+    pub color: Color,
+
+    /// Thickness of the tip (diameter at the bottom of the arrow)
+    pub tip_thickness: f32,
+
+    /// Length of the tip
+    pub tip_length: f32,
+}
+
+impl RotArrow {
+    pub fn new(rotation: Quat, radius: f32) -> Self {
+        Self {
+            rotation,
+            radius,
+            thickness: 0.1,
+            color: Color::WHITE,
+            tip_thickness: 0.075,
+            tip_length: 0.15,
+        }
+    }
+
+    pub const fn with_thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// This component is used by the plugin internally
+/// and marks one of the segments making up the arc
+/// (which is a cylinder).
+#[derive(Component)]
+pub(crate) struct RotArrowSegment {}
+
+/// This component is used by the plugin internally
+/// and marks the tip of the arrow
+/// (which is a cone).
+#[derive(Component)]
+pub(crate) struct RotArrowTip {}
+
+/// This component is used by the plugin internally
+/// to store the Entity ids for the arrow parts.
+/// This is used when the arrow is removed
+/// to find the other entities to also remove.
+#[derive(Component, Clone, Debug)]
+pub(crate) struct RotArrowParts {
+    segments: Vec<Entity>,
+    tip: Entity,
+}
+
+pub(crate) fn on_attach_rot_arrow(
+    mut commands: Commands,
+    arrow_meshes: Res<VecArrowMeshes>,
+    mut arrow_materials: ResMut<VecArrowMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    query: Query<(Entity, Option<&GlobalTransform>, &RotArrow), Added<RotArrow>>,
+) {
+    // When a rot-arrow is added,
+    // we need to create the arc segments
+    // and a cone, reusing the shared unit meshes and the cached material for
+    // this arrow's color instead of allocating one of each per segment.
+    for (new_parent_entity, parent_global_transform, new_arrow) in query.iter() {
+        // Ensure the parent has Visibility and Transform components
+        commands
+            .entity(new_parent_entity)
+            .insert_if_new(Visibility::Inherited)
+            .insert_if_new(Transform::default());
+
+        let segment_transforms =
+            get_segment_transforms(new_arrow, parent_global_transform.cloned());
+
+        let material = arrow_materials.get_or_insert(new_arrow.color, &mut materials);
+
+        let segments = segment_transforms
+            .iter()
+            .map(|segment_transform| {
+                commands
+                    .spawn((
+                        Mesh3d(arrow_meshes.cylinder()),
+                        MeshMaterial3d(material.clone()),
+                        *segment_transform,
+                        RotArrowSegment {},
+                        Name::new(format!("RotArrowSegment for {}", new_parent_entity)),
+                    ))
+                    .id()
+            })
+            .collect();
+
+        let tip = commands
+            .spawn((
+                Mesh3d(arrow_meshes.cone()),
+                MeshMaterial3d(material),
+                get_tip_transform(new_arrow, parent_global_transform.cloned()),
+                Name::new(format!("RotArrowTip for {}", new_parent_entity)),
+                RotArrowTip {},
+            ))
+            .id();
+
+        commands
+            .entity(new_parent_entity)
+            .insert(RotArrowParts { segments, tip });
+    }
+}
+
+pub(crate) fn on_remove_rot_arrow(
+    mut commands: Commands,
+    mut parents_with_removed_arrows: RemovedComponents<RotArrow>,
+    parent_state_query: Query<Option<&RotArrowParts>>,
+) {
+    for entity in parents_with_removed_arrows.read() {
+        // entity has just had its RotArrow component removed by the user.
+        // If the entity has despawned completely, then we can't find its children.
+        // Otherwise, we can read the RotArrowParts,
+        // which we use to keep track of the arrow components.
+        if let Ok(Some(RotArrowParts { segments, tip })) = parent_state_query.get(entity) {
+            // Despawn the arrow parts
+            for segment in segments {
+                commands.entity(*segment).despawn();
+            }
+            commands.entity(*tip).despawn();
+
+            // Remove RotArrowParts from the parent
+            commands.entity(entity).remove::<RotArrowParts>();
+        }
+    }
+}
+
+pub(crate) fn update_rot_arrow(
+    mut commands: Commands,
+    parent_arrows: Query<(Option<&GlobalTransform>, &RotArrow, &RotArrowParts)>,
+    mut segment_query: Query<&mut Transform, (With<RotArrowSegment>, Without<RotArrowTip>)>,
+    mut tip_query: Query<&mut Transform, (With<RotArrowTip>, Without<RotArrowSegment>)>,
+    mut arrow_materials: ResMut<VecArrowMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    segment_material_query: Query<&MeshMaterial3d<StandardMaterial>, With<RotArrowSegment>>,
+    changed_parents: Query<(&RotArrow, &RotArrowParts), Changed<RotArrow>>,
+) {
+    for (parent_global_transform, rot_arrow, parts) in parent_arrows.iter() {
+        let new_segment_transforms =
+            get_segment_transforms(rot_arrow, parent_global_transform.copied());
+        for (segment, new_segment_transform) in parts.segments.iter().zip(new_segment_transforms) {
+            *segment_query.get_mut(*segment).unwrap() = new_segment_transform;
+        }
+
+        let new_tip_transform = get_tip_transform(rot_arrow, parent_global_transform.copied());
+        *tip_query.get_mut(parts.tip).unwrap() = new_tip_transform;
+    }
+
+    // Same guard as `vec_arrow::update_vec_arrow`: only touch the material
+    // when the cached handle for the current color differs from what's
+    // already applied, instead of writing `base_color` into it every frame
+    // for every segment.
+    for (rot_arrow, parts) in changed_parents.iter() {
+        let material = arrow_materials.get_or_insert(rot_arrow.color, &mut materials);
+        let already_applied = parts
+            .segments
+            .first()
+            .and_then(|segment| segment_material_query.get(*segment).ok())
+            .is_some_and(|current| current.0 == material);
+        if already_applied {
+            continue;
+        }
+        for segment in &parts.segments {
+            commands
+                .entity(*segment)
+                .insert(MeshMaterial3d(material.clone()));
+        }
+        commands.entity(parts.tip).insert(MeshMaterial3d(material));
+    }
+}
+
+/// Builds an orthonormal basis `(u, v)` perpendicular to the rotation axis
+/// `axis`, by picking a seed vector that can't be parallel to it.
+fn arc_basis(axis: Vec3) -> (Vec3, Vec3) {
+    // Vec3::X can only be parallel to axis when axis is mostly along X,
+    // in which case Vec3::Y is guaranteed not to be.
+    let seed = if axis.x.abs() < 0.9 { Vec3::X } else { Vec3::Y };
+    let u = seed.cross(axis).normalize();
+    let v = axis.cross(u);
+    (u, v)
+}
+
+/// Returns the point on the arc at angle `t` (measured from `0` to `theta`).
+fn arc_point(u: Vec3, v: Vec3, radius: f32, t: f32) -> Vec3 {
+    radius * (t.cos() * u + t.sin() * v)
+}
+
+/// Returns the (unit-length) tangent direction of the arc at angle `t`.
+fn arc_tangent(u: Vec3, v: Vec3, t: f32) -> Vec3 {
+    -t.sin() * u + t.cos() * v
+}
+
+/// Places a point sampled in the arc's own local space into world space,
+/// by applying the parent's rotation and translation selectively (the same
+/// selective transform used by [`crate::vec_arrow`]'s `Local` branch,
+/// leaving scale out).
+fn resolve_arc_point(local_point: Vec3, parent_transform: Option<GlobalTransform>) -> Vec3 {
+    let parent_transform = parent_transform.unwrap_or_default();
+    parent_transform.rotation() * local_point + parent_transform.translation()
+}
+
+/// Computes the transforms for every segment of the arc,
+/// each a cylinder stretching between two consecutive sampled arc points,
+/// placed relative to the owning entity's [`GlobalTransform`].
+///
+/// If the rotation's angle is ~0, every segment is returned with zero scale,
+/// mirroring the zero-vector guard used for [`crate::vec_arrow::VecArrow`].
+fn get_segment_transforms(
+    rot_arrow: &RotArrow,
+    parent_transform: Option<GlobalTransform>,
+) -> Vec<Transform> {
+    let (axis, angle) = rot_arrow.rotation.to_axis_angle();
+
+    if angle.abs() < f32::EPSILON {
+        return vec![Transform::from_scale(Vec3::ZERO); ROT_ARROW_SEGMENTS];
+    }
+
+    let (u, v) = arc_basis(axis);
+
+    (0..ROT_ARROW_SEGMENTS)
+        .map(|i| {
+            let t_start = angle * (i as f32 / ROT_ARROW_SEGMENTS as f32);
+            let t_end = angle * ((i + 1) as f32 / ROT_ARROW_SEGMENTS as f32);
+
+            let start =
+                resolve_arc_point(arc_point(u, v, rot_arrow.radius, t_start), parent_transform);
+            let end = resolve_arc_point(arc_point(u, v, rot_arrow.radius, t_end), parent_transform);
+
+            let segment = end - start;
+            let Some(normalized) = segment.try_normalize() else {
+                // Consecutive points coincide: skip this segment.
+                return Transform::from_scale(Vec3::ZERO);
+            };
+
+            let midpoint = (start + end) / 2.0;
+            let mut transform = Transform::from_translation(midpoint);
+            transform.rotate(Quat::from_rotation_arc(Vec3::Y, normalized));
+            // X, Z scale matches the shaft thickness, Y scale the segment's
+            // length, mirroring the tip's `get_tip_transform` scaling.
+            transform.scale = Vec3::new(rot_arrow.thickness, segment.length(), rot_arrow.thickness);
+            transform
+        })
+        .collect()
+}
+
+/// Computes the transform for the cone tip at the end of the arc, oriented
+/// along the arc's final tangent and placed relative to the owning entity's
+/// [`GlobalTransform`].
+fn get_tip_transform(rot_arrow: &RotArrow, parent_transform: Option<GlobalTransform>) -> Transform {
+    let (axis, angle) = rot_arrow.rotation.to_axis_angle();
+
+    if angle.abs() < f32::EPSILON {
+        return Transform::from_scale(Vec3::ZERO);
+    }
+
+    let (u, v) = arc_basis(axis);
+    let tip_point = resolve_arc_point(arc_point(u, v, rot_arrow.radius, angle), parent_transform);
+    let tangent = parent_transform.unwrap_or_default().rotation() * arc_tangent(u, v, angle);
+
+    let mut transform = Transform::from_translation(tip_point);
+    transform.rotate(Quat::from_rotation_arc(Vec3::Y, tangent));
+    transform.scale = Vec3::new(
+        rot_arrow.tip_thickness,
+        rot_arrow.tip_length,
+        rot_arrow.tip_thickness,
+    );
+    transform
+}
@@ -1,5 +1,79 @@
+use std::collections::HashMap;
+
 use bevy::prelude::*;
 
+/// Unit-sized meshes shared by every [`VecArrow`], created once at startup
+/// by [`setup_vec_arrow_meshes`]. Arrows scale and rotate these via their
+/// `Transform` instead of each allocating their own `Cylinder`/`Cone` mesh.
+#[derive(Resource)]
+pub(crate) struct VecArrowMeshes {
+    cylinder: Handle<Mesh>,
+    cone: Handle<Mesh>,
+}
+
+impl VecArrowMeshes {
+    /// The shared unit-cylinder mesh used for arrow shafts/segments.
+    pub(crate) fn cylinder(&self) -> Handle<Mesh> {
+        self.cylinder.clone()
+    }
+
+    /// The shared unit-cone mesh used for arrow tips.
+    pub(crate) fn cone(&self) -> Handle<Mesh> {
+        self.cone.clone()
+    }
+}
+
+pub(crate) fn setup_vec_arrow_meshes(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
+    commands.insert_resource(VecArrowMeshes {
+        cylinder: meshes.add(Cylinder::new(1.0, 1.0)),
+        cone: meshes.add(Cone::new(1.0, 1.0)),
+    });
+}
+
+/// A [`Color`]'s components, bit-cast to integers so the color can be used
+/// as a `HashMap` key (`Color`'s `f32` fields aren't `Eq`/`Hash`).
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+struct ColorKey([u32; 4]);
+
+impl From<Color> for ColorKey {
+    fn from(color: Color) -> Self {
+        let [r, g, b, a] = color.to_linear().to_f32_array();
+        Self([r.to_bits(), g.to_bits(), b.to_bits(), a.to_bits()])
+    }
+}
+
+/// Upper bound on the number of distinct colors [`VecArrowMaterials`] will
+/// cache at once. Without this, an arrow whose color is mutated every frame
+/// (e.g. a debug arrow cycling hue) would grow the cache, and the backing
+/// `Assets<StandardMaterial>` store, by one handle per distinct color
+/// forever. Once the cache hits this size it's dropped and rebuilt from
+/// scratch, trading an occasional one-frame re-allocation for bounded
+/// memory growth.
+const MAX_CACHED_MATERIALS: usize = 256;
+
+/// Caches one [`StandardMaterial`] handle per distinct [`VecArrow::color`],
+/// so arrows that share a color also share a material instead of each
+/// allocating their own.
+#[derive(Resource, Default)]
+pub(crate) struct VecArrowMaterials(HashMap<ColorKey, Handle<StandardMaterial>>);
+
+impl VecArrowMaterials {
+    pub(crate) fn get_or_insert(
+        &mut self,
+        color: Color,
+        materials: &mut Assets<StandardMaterial>,
+    ) -> Handle<StandardMaterial> {
+        let key = color.into();
+        if !self.0.contains_key(&key) && self.0.len() >= MAX_CACHED_MATERIALS {
+            self.0.clear();
+        }
+        self.0
+            .entry(key)
+            .or_insert_with(|| materials.add(color))
+            .clone()
+    }
+}
+
 /// An arrow starting at the object's transform,
 /// pointing at a particular position.
 #[derive(Component)]
@@ -46,12 +120,21 @@ impl VecArrow {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TargetCoordinateSpace {
     /// Global coordinate space
     Global,
 
     /// Local to the object
     Local,
+
+    /// Track the live [`GlobalTransform`] of another entity.
+    ///
+    /// Every frame, the arrow points at the referenced entity's world-space
+    /// translation. If the entity has no [`GlobalTransform`] (for example,
+    /// because it was despawned), the arrow falls back to the zero-scale
+    /// behavior used for a zero-length target, so it simply disappears.
+    Entity(Entity),
 }
 
 /// This component is used by the plugin internally
@@ -78,13 +161,15 @@ pub(crate) struct VecArrowParts {
 
 pub(crate) fn on_attach_vec_arrow(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
+    arrow_meshes: Res<VecArrowMeshes>,
+    mut arrow_materials: ResMut<VecArrowMaterials>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     query: Query<(Entity, Option<&GlobalTransform>, &VecArrow), Added<VecArrow>>,
+    target_transforms: Query<&GlobalTransform>,
 ) {
     // When a vec-arrow is added,
-    // we need to create a cylinder
-    // and a cone.
+    // we spawn a cylinder and a cone, reusing the shared unit meshes
+    // and the cached material for this arrow's color.
     for (new_parent_entity, parent_global_transform, new_arrow) in query.iter() {
         // Ensure the parent has Visibility and Transform components
         commands
@@ -92,16 +177,27 @@ pub(crate) fn on_attach_vec_arrow(
             .insert_if_new(Visibility::Inherited)
             .insert_if_new(Transform::default());
 
+        let resolved_target = resolve_target(
+            &new_arrow.target_coordinate_space,
+            &new_arrow.target,
+            &target_transforms,
+        );
+
+        let material = arrow_materials.get_or_insert(new_arrow.color, &mut materials);
+
         let body = commands
             .spawn((
-                Mesh3d(meshes.add(Cylinder::new(0.01, 1.0))),
-                // Mesh3d(meshes.add(Cone::new(0.1, 1.0))),
-                MeshMaterial3d(materials.add(new_arrow.color)),
-                get_body_transform(
-                    parent_global_transform.cloned(),
-                    &new_arrow.target,
-                    &new_arrow.target_coordinate_space,
-                ),
+                Mesh3d(arrow_meshes.cylinder.clone()),
+                MeshMaterial3d(material.clone()),
+                match resolved_target {
+                    Some((target, is_global)) => get_body_transform(
+                        parent_global_transform.cloned(),
+                        target,
+                        is_global,
+                        new_arrow.thickness,
+                    ),
+                    None => Transform::from_scale(Vec3::ZERO),
+                },
                 VecArrowBody {},
                 Name::new(format!("VecArrowBody for {}", new_parent_entity)),
             ))
@@ -109,15 +205,18 @@ pub(crate) fn on_attach_vec_arrow(
 
         let tip = commands
             .spawn((
-                Mesh3d(meshes.add(Cone::new(1.0, 1.0))),
-                MeshMaterial3d(materials.add(new_arrow.color)),
-                get_tip_transform(
-                    parent_global_transform.cloned(),
-                    &new_arrow.target,
-                    &new_arrow.target_coordinate_space,
-                    new_arrow.tip_length,
-                    new_arrow.tip_thickness,
-                ),
+                Mesh3d(arrow_meshes.cone.clone()),
+                MeshMaterial3d(material),
+                match resolved_target {
+                    Some((target, is_global)) => get_tip_transform(
+                        parent_global_transform.cloned(),
+                        target,
+                        is_global,
+                        new_arrow.tip_length,
+                        new_arrow.tip_thickness,
+                    ),
+                    None => Transform::from_scale(Vec3::ZERO),
+                },
                 Name::new(format!("VecArrowTip for {}", new_parent_entity)),
                 VecArrowTip {},
             ))
@@ -151,67 +250,153 @@ pub(crate) fn on_remove_vec_arrow(
 }
 
 pub(crate) fn update_vec_arrow(
+    mut commands: Commands,
     parent_transforms: Query<(&GlobalTransform, &VecArrow, &VecArrowParts)>,
-    mut body_query: Query<
-        (
-            &mut Transform,
-            &MeshMaterial3d<StandardMaterial>,
-            &VecArrowBody,
-        ),
-        Without<VecArrowTip>,
-    >,
-    mut tip_query: Query<
-        (
-            &mut Transform,
-            &MeshMaterial3d<StandardMaterial>,
-            &VecArrowTip,
-        ),
-        Without<VecArrowBody>,
-    >,
+    target_transforms: Query<&GlobalTransform>,
+    mut body_query: Query<&mut Transform, (With<VecArrowBody>, Without<VecArrowTip>)>,
+    mut tip_query: Query<&mut Transform, (With<VecArrowTip>, Without<VecArrowBody>)>,
+    mut arrow_materials: ResMut<VecArrowMaterials>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    body_material_query: Query<&MeshMaterial3d<StandardMaterial>, With<VecArrowBody>>,
+    changed_parents: Query<(&VecArrow, &VecArrowParts), Changed<VecArrow>>,
 ) {
     for (global_transform, vec_arrow, parts) in parent_transforms.iter() {
-        let new_body_transform = get_body_transform(
-            Some(*global_transform),
-            &vec_arrow.target,
+        let resolved_target = resolve_target(
             &vec_arrow.target_coordinate_space,
-        );
-        let new_tip_transform = get_tip_transform(
-            Some(*global_transform),
             &vec_arrow.target,
-            &vec_arrow.target_coordinate_space,
-            vec_arrow.tip_length,
-            vec_arrow.tip_thickness,
+            &target_transforms,
         );
 
-        let (mut body_transform, body_material, _) = body_query.get_mut(parts.body).unwrap();
-        *body_transform = new_body_transform;
-        if let Some(material) = materials.get_mut(&body_material.0) {
-            material.base_color = vec_arrow.color;
+        let new_body_transform = match resolved_target {
+            Some((target, is_global)) => get_body_transform(
+                Some(*global_transform),
+                target,
+                is_global,
+                vec_arrow.thickness,
+            ),
+            None => Transform::from_scale(Vec3::ZERO),
+        };
+        let new_tip_transform = match resolved_target {
+            Some((target, is_global)) => get_tip_transform(
+                Some(*global_transform),
+                target,
+                is_global,
+                vec_arrow.tip_length,
+                vec_arrow.tip_thickness,
+            ),
+            None => Transform::from_scale(Vec3::ZERO),
+        };
+
+        *body_query.get_mut(parts.body).unwrap() = new_body_transform;
+        *tip_query.get_mut(parts.tip).unwrap() = new_tip_transform;
+    }
+
+    // `Changed<VecArrow>` fires whenever any field changed, not just `color`,
+    // so re-resolve the cached handle for the new color and compare it
+    // against what's already on the body before touching the material
+    // components — that's what actually keeps this to once per color change
+    // rather than once per frame for an arrow mutated every frame.
+    for (vec_arrow, parts) in changed_parents.iter() {
+        let material = arrow_materials.get_or_insert(vec_arrow.color, &mut materials);
+        let already_applied = body_material_query
+            .get(parts.body)
+            .is_ok_and(|current| current.0 == material);
+        if already_applied {
+            continue;
         }
+        commands
+            .entity(parts.body)
+            .insert(MeshMaterial3d(material.clone()));
+        commands.entity(parts.tip).insert(MeshMaterial3d(material));
+    }
+}
 
-        let (mut tip_transform, tip_material, _) = tip_query.get_mut(parts.tip).unwrap();
-        *tip_transform = new_tip_transform;
-        if let Some(material) = materials.get_mut(&tip_material.0) {
-            material.base_color = vec_arrow.color;
+/// Resolves a [`TargetCoordinateSpace`] and its accompanying target vector
+/// down to a concrete `(target, is_global)` pair that [`get_body_transform`]
+/// and [`get_tip_transform`] can work with.
+///
+/// `TargetCoordinateSpace::Entity` is looked up in `target_transforms`; if
+/// the referenced entity has no [`GlobalTransform`] (for example, it was
+/// despawned), `None` is returned so the caller can fall back to the
+/// zero-scale behavior instead of pointing at a stale position.
+fn resolve_target(
+    target_coordinate_space: &TargetCoordinateSpace,
+    target: &Vec3,
+    target_transforms: &Query<&GlobalTransform>,
+) -> Option<(Vec3, bool)> {
+    match target_coordinate_space {
+        TargetCoordinateSpace::Local => Some((*target, false)),
+        TargetCoordinateSpace::Global => Some((*target, true)),
+        TargetCoordinateSpace::Entity(entity) => target_transforms
+            .get(*entity)
+            .ok()
+            .map(|global_transform| (global_transform.translation(), true)),
+    }
+}
+
+/// Draws every [`VecArrow`] as immediate-mode line segments instead of
+/// spawning [`VecArrowBody`]/[`VecArrowTip`] meshes. Used in place of
+/// [`on_attach_vec_arrow`]/[`on_remove_vec_arrow`]/[`update_vec_arrow`] when
+/// [`crate::ArrowRenderMode::Gizmo`] is selected.
+#[cfg(feature = "gizmo_render")]
+pub(crate) fn draw_vec_arrow_gizmos(
+    mut gizmos: Gizmos,
+    query: Query<(&GlobalTransform, &VecArrow)>,
+    target_transforms: Query<&GlobalTransform>,
+) {
+    for (global_transform, vec_arrow) in query.iter() {
+        let Some((target, is_global)) = resolve_target(
+            &vec_arrow.target_coordinate_space,
+            &vec_arrow.target,
+            &target_transforms,
+        ) else {
+            // Referenced entity is missing or despawned: draw nothing.
+            continue;
+        };
+
+        // Same selective rotation+translation math as the `Local` branch of
+        // `get_body_transform`/`get_tip_transform`, just applied to a single
+        // point instead of a mesh transform.
+        let origin = global_transform.translation();
+        let tip = if is_global {
+            target
+        } else {
+            global_transform.rotation() * target + origin
+        };
+
+        let Some(direction) = (tip - origin).try_normalize() else {
+            // Zero-length arrow: draw nothing, matching the zero-scale guard
+            // used by the mesh rendering path.
+            continue;
+        };
+
+        gizmos.line(origin, tip, vec_arrow.color);
+
+        // Splay a few short lines back from the tip to suggest a cone.
+        let rotation = Quat::from_rotation_arc(Vec3::Y, direction);
+        let back = direction * vec_arrow.tip_length;
+        for spread_axis in [Vec3::X, Vec3::Z] {
+            let spread = (rotation * spread_axis) * vec_arrow.tip_thickness;
+            gizmos.line(tip, tip - back + spread, vec_arrow.color);
+            gizmos.line(tip, tip - back - spread, vec_arrow.color);
         }
     }
 }
 
 fn get_body_transform(
     parent_transform: Option<GlobalTransform>,
-    target: &Vec3,
-    target_coordinate_space: &TargetCoordinateSpace,
+    target: Vec3,
+    is_global: bool,
+    thickness: f32,
 ) -> Transform {
     // If the target vector is in the local coordinate system,
     // then it looks like a vector from the origin directly to the target.
     // However, if it's in the global coordinate system,
     // then the arrow is shifted in the opposite direction.
-    let target = match target_coordinate_space {
-        TargetCoordinateSpace::Local => *target,
-        TargetCoordinateSpace::Global => {
-            -parent_transform.unwrap_or_default().translation() + *target
-        }
+    let target = if is_global {
+        -parent_transform.unwrap_or_default().translation() + target
+    } else {
+        target
     };
 
     let Some(normalized) = target.try_normalize() else {
@@ -231,42 +416,41 @@ fn get_body_transform(
 
     // The Y scale of the cylinder is equal to the distance
     // between the parent's position and the target
-    // (because unscaled, the height is equal to 1)
-    let my_scale = Vec3::new(1.0, target.length(), 1.0);
+    // (because unscaled, the height is equal to 1).
+    // X, Z scale matches the shaft thickness, mirroring how
+    // `get_tip_transform` scales the unit cone.
+    let my_scale = Vec3::new(thickness, target.length(), thickness);
     let mut my_local_transform = my_local_transform.with_scale(my_scale);
 
-    match target_coordinate_space {
-        TargetCoordinateSpace::Global => {
-            // If the target is in the global coordinate space,
-            // then our local transform is already correct.
-            // All we need to do is translate it to match the parent's origin.
-            my_local_transform.translation += parent_transform.unwrap_or_default().translation();
-            my_local_transform
-        }
-        TargetCoordinateSpace::Local => {
-            // If the target is in the local coordinate space,
-            // then we need to apply the parent's transform
-            // to our current one.
-            // We have to do this selectively, only doing translation and rotation.
-            let parent_transform = parent_transform.unwrap_or_default();
-            let mut my_global_transform = my_local_transform;
-            my_global_transform.translation = parent_transform
-                .rotation()
-                .mul_vec3(my_global_transform.translation)
-                + parent_transform.translation();
-            my_global_transform.rotation = parent_transform
-                .rotation()
-                .mul_quat(my_global_transform.rotation);
-
-            my_global_transform
-        }
+    if is_global {
+        // If the target is in the global coordinate space,
+        // then our local transform is already correct.
+        // All we need to do is translate it to match the parent's origin.
+        my_local_transform.translation += parent_transform.unwrap_or_default().translation();
+        my_local_transform
+    } else {
+        // If the target is in the local coordinate space,
+        // then we need to apply the parent's transform
+        // to our current one.
+        // We have to do this selectively, only doing translation and rotation.
+        let parent_transform = parent_transform.unwrap_or_default();
+        let mut my_global_transform = my_local_transform;
+        my_global_transform.translation = parent_transform
+            .rotation()
+            .mul_vec3(my_global_transform.translation)
+            + parent_transform.translation();
+        my_global_transform.rotation = parent_transform
+            .rotation()
+            .mul_quat(my_global_transform.rotation);
+
+        my_global_transform
     }
 }
 
 fn get_tip_transform(
     parent_transform: Option<GlobalTransform>,
-    target: &Vec3,
-    target_coordinate_space: &TargetCoordinateSpace,
+    target: Vec3,
+    is_global: bool,
     tip_length: f32,
     tip_thickness: f32,
 ) -> Transform {
@@ -274,11 +458,10 @@ fn get_tip_transform(
     // then it looks like a vector from the origin directly to the target.
     // However, if it's in the global coordinate system,
     // then the arrow is shifted in the opposite direction.
-    let target = match target_coordinate_space {
-        TargetCoordinateSpace::Local => *target,
-        TargetCoordinateSpace::Global => {
-            -parent_transform.unwrap_or_default().translation() + *target
-        }
+    let target = if is_global {
+        -parent_transform.unwrap_or_default().translation() + target
+    } else {
+        target
     };
 
     let Some(normalized) = target.try_normalize() else {
@@ -294,31 +477,28 @@ fn get_tip_transform(
     // Y transform to match the length
     my_local_transform.scale = Vec3::new(tip_thickness, tip_length, tip_thickness);
 
-    match target_coordinate_space {
-        TargetCoordinateSpace::Global => {
-            // If the target is in the global coordinate space,
-            // then our local transform is already correct,
-            // so we return that.
-            my_local_transform.translation += parent_transform.unwrap_or_default().translation();
-            my_local_transform
-        }
-        TargetCoordinateSpace::Local => {
-            // If the target is in the local coordinate space,
-            // then we need to apply the parent's transform
-            // to our current one.
-            // We have to do this selectively, only doing translation and rotation.
-            let parent_transform = parent_transform.unwrap_or_default();
-            let mut my_global_transform = my_local_transform;
-
-            my_global_transform.translation = parent_transform
-                .rotation()
-                .mul_vec3(my_global_transform.translation)
-                + parent_transform.translation();
-            my_global_transform.rotation = parent_transform
-                .rotation()
-                .mul_quat(my_global_transform.rotation);
-
-            my_global_transform
-        }
+    if is_global {
+        // If the target is in the global coordinate space,
+        // then our local transform is already correct,
+        // so we return that.
+        my_local_transform.translation += parent_transform.unwrap_or_default().translation();
+        my_local_transform
+    } else {
+        // If the target is in the local coordinate space,
+        // then we need to apply the parent's transform
+        // to our current one.
+        // We have to do this selectively, only doing translation and rotation.
+        let parent_transform = parent_transform.unwrap_or_default();
+        let mut my_global_transform = my_local_transform;
+
+        my_global_transform.translation = parent_transform
+            .rotation()
+            .mul_vec3(my_global_transform.translation)
+            + parent_transform.translation();
+        my_global_transform.rotation = parent_transform
+            .rotation()
+            .mul_quat(my_global_transform.rotation);
+
+        my_global_transform
     }
 }